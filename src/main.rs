@@ -19,13 +19,28 @@ fn print_banner(lines: &[&str]) {
 fn main() -> Result<(), String> {
     let args: Vec<String> = env::args().skip(1).collect();
 
-    if args.len() < 2 {
-        return Err("Usage: program <flag1> <file1> [<flag2> <file2> ...]".to_string());
+    if args.is_empty() {
+        return Err("Usage: program <flag1> <file1> [<flag2> <file2> ...] | -repl".to_string());
     }
 
     let mut i = 0;
     while i < args.len() {
         let flag = &args[i];
+
+        if flag == "-repl" {
+            print_banner(&["Running Task 3 REPL (Boolean functions)"]);
+            task3::run_repl()?;
+            i += 1;
+            continue;
+        }
+
+        if flag == "-t1repl" {
+            print_banner(&["Running Task 1 REPL (Set calculator)"]);
+            task1::run_task_1_repl()?;
+            i += 1;
+            continue;
+        }
+
         let file_path = &args[i + 1];
         i += 2;
 
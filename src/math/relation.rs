@@ -293,3 +293,127 @@ impl Relation {
         classes
     }
 }
+
+impl Relation {
+    // Build a fresh relation over the same base from a ready-made bitset matrix,
+    // re-creating the index map for large bases and refreshing the cached
+    // is_full / is_empty flags.
+    fn with_matrix(&self, matrix: Vec<Vec<u64>>) -> Relation {
+        let mut rel = Relation {
+            base: self.base.clone(),
+            matrix,
+            index_map: None,
+            is_full: false,
+            is_empty: false,
+        };
+        if rel.base.len() > 128 {
+            rel.build_index_map();
+        }
+        rel.update_flags();
+        rel
+    }
+
+    /// Transitive closure via Warshall: `O(n^3 / 64)` by OR-ing whole rows
+    /// together a word at a time. No diagonal pairs are added beyond those the
+    /// transitivity itself implies.
+    pub fn transitive_closure(&self) -> Relation {
+        let n = self.matrix.len();
+        let mut matrix = self.matrix.clone();
+        for k in 0..n {
+            let words = matrix[k].len();
+            for i in 0..n {
+                let (word, bit) = (k / 64, k % 64);
+                if (matrix[i][word] >> bit) & 1 == 1 {
+                    for w in 0..words {
+                        matrix[i][w] |= matrix[k][w];
+                    }
+                }
+            }
+        }
+        self.with_matrix(matrix)
+    }
+
+    /// Add every `(i, i)` pair so the result is reflexive.
+    pub fn reflexive_closure(&self) -> Relation {
+        let n = self.matrix.len();
+        let mut matrix = self.matrix.clone();
+        for i in 0..n {
+            matrix[i][i / 64] |= 1u64 << (i % 64);
+        }
+        self.with_matrix(matrix)
+    }
+
+    /// OR in the transpose so the result is symmetric.
+    pub fn symmetric_closure(&self) -> Relation {
+        let n = self.matrix.len();
+        let mut matrix = self.matrix.clone();
+        for i in 0..n {
+            for j in 0..n {
+                if self.get_pair(i, j) {
+                    matrix[j][i / 64] |= 1u64 << (i % 64);
+                }
+            }
+        }
+        self.with_matrix(matrix)
+    }
+
+    /// Elementwise OR of two relations over the same base.
+    pub fn union(&self, other: &Relation) -> Relation {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// Elementwise AND of two relations over the same base.
+    pub fn intersection(&self, other: &Relation) -> Relation {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// Pairs present in `self` but not in `other`.
+    pub fn difference(&self, other: &Relation) -> Relation {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    fn combine(&self, other: &Relation, op: impl Fn(u64, u64) -> u64) -> Relation {
+        let n = self.matrix.len();
+        let words = if n == 0 { 0 } else { self.matrix[0].len() };
+        let mut matrix = vec![vec![0u64; words]; n];
+        for i in 0..n {
+            for w in 0..words {
+                matrix[i][w] = op(self.matrix[i][w], other.matrix[i][w]);
+            }
+        }
+        self.with_matrix(matrix)
+    }
+
+    /// Inverse relation `(j, i) in R^-1 iff (i, j) in R` — the matrix transpose.
+    pub fn inverse(&self) -> Relation {
+        let n = self.matrix.len();
+        let words = if n == 0 { 0 } else { self.matrix[0].len() };
+        let mut matrix = vec![vec![0u64; words]; n];
+        for i in 0..n {
+            for j in 0..n {
+                if self.get_pair(i, j) {
+                    matrix[j][i / 64] |= 1u64 << (i % 64);
+                }
+            }
+        }
+        self.with_matrix(matrix)
+    }
+
+    /// Composition `R . S`: `(i, j)` is set iff some `k` has `R(i, k)` and
+    /// `S(k, j)`, computed by OR-ing S's row `k` into result row `i`.
+    pub fn compose(&self, other: &Relation) -> Relation {
+        let n = self.matrix.len();
+        let words = if n == 0 { 0 } else { self.matrix[0].len() };
+        let mut matrix = vec![vec![0u64; words]; n];
+        for i in 0..n {
+            for k in 0..n {
+                if self.get_pair(i, k) {
+                    for w in 0..words {
+                        matrix[i][w] |= other.matrix[k][w];
+                    }
+                }
+            }
+        }
+        self.with_matrix(matrix)
+    }
+}
@@ -1,16 +1,24 @@
 use std::fmt;
 
-#[derive(Default, Clone, Debug, PartialEq, Eq)]
-pub struct Set {
-    elements: Vec<char>,
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Set<T = char> {
+    elements: Vec<T>,
 }
 
-impl Set {
+impl<T: Ord + Clone> Default for Set<T> {
+    fn default() -> Self {
+        Set {
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<T: Ord + Clone> Set<T> {
     pub fn new() -> Self {
         Self::default()
     }
 
-    pub fn add(&mut self, x: char) -> &mut Self {
+    pub fn add(&mut self, x: T) -> &mut Self {
         match self.elements.binary_search(&x) {
             Ok(_) => {}
             Err(pos) => self.elements.insert(pos, x),
@@ -18,7 +26,7 @@ impl Set {
         self
     }
 
-    pub fn remove(&mut self, x: char) -> &mut Self {
+    pub fn remove(&mut self, x: T) -> &mut Self {
         if let Ok(pos) = self.elements.binary_search(&x) {
             self.elements.remove(pos);
         }
@@ -30,7 +38,7 @@ impl Set {
         self
     }
 
-    pub fn power(&self) -> Vec<Set> {
+    pub fn power(&self) -> Vec<Set<T>> {
         let n = self.elements.len();
         if n > 64 {
             panic!("Elements count in set is too big");
@@ -41,7 +49,7 @@ impl Set {
             let mut subset = Set::new();
             for i in 0..n {
                 if (mask & (1 << i)) != 0 {
-                    subset.elements.push(self.elements[i]);
+                    subset.elements.push(self.elements[i].clone());
                 }
             }
             result.push(subset);
@@ -49,19 +57,19 @@ impl Set {
         result
     }
 
-    pub fn union(&self, other: &Set) -> Set {
+    pub fn union(&self, other: &Set<T>) -> Set<T> {
         let mut result = Vec::new();
         let mut i = 0;
         let mut j = 0;
         while i < self.elements.len() && j < other.elements.len() {
             if self.elements[i] < other.elements[j] {
-                result.push(self.elements[i]);
+                result.push(self.elements[i].clone());
                 i += 1;
             } else if self.elements[i] > other.elements[j] {
-                result.push(other.elements[j]);
+                result.push(other.elements[j].clone());
                 j += 1;
             } else {
-                result.push(self.elements[i]);
+                result.push(self.elements[i].clone());
                 i += 1;
                 j += 1;
             }
@@ -71,7 +79,7 @@ impl Set {
         Set { elements: result }
     }
 
-    pub fn intersection(&self, other: &Set) -> Set {
+    pub fn intersection(&self, other: &Set<T>) -> Set<T> {
         let mut result = Vec::new();
         let mut i = 0;
         let mut j = 0;
@@ -81,7 +89,7 @@ impl Set {
             } else if self.elements[i] > other.elements[j] {
                 j += 1;
             } else {
-                result.push(self.elements[i]);
+                result.push(self.elements[i].clone());
                 i += 1;
                 j += 1;
             }
@@ -89,13 +97,13 @@ impl Set {
         Set { elements: result }
     }
 
-    pub fn difference(&self, other: &Set) -> Set {
+    pub fn difference(&self, other: &Set<T>) -> Set<T> {
         let mut result = Vec::new();
         let mut i = 0;
         let mut j = 0;
         while i < self.elements.len() && j < other.elements.len() {
             if self.elements[i] < other.elements[j] {
-                result.push(self.elements[i]);
+                result.push(self.elements[i].clone());
                 i += 1;
             } else if self.elements[i] > other.elements[j] {
                 j += 1;
@@ -108,7 +116,7 @@ impl Set {
         Set { elements: result }
     }
 
-    pub fn is_subset(&self, other: &Set) -> bool {
+    pub fn is_subset(&self, other: &Set<T>) -> bool {
         let mut i = 0;
         let mut j = 0;
         while i < self.elements.len() && j < other.elements.len() {
@@ -124,6 +132,19 @@ impl Set {
         i == self.elements.len()
     }
 
+    /// Cartesian product `self × other`, whose elements are the ordered pairs
+    /// `(a, b)`. Because both operands keep their sorted-vector invariant and
+    /// tuples compare lexicographically, the result is already sorted.
+    pub fn cartesian_product<U: Ord + Clone>(&self, other: &Set<U>) -> Set<(T, U)> {
+        let mut result = Vec::with_capacity(self.elements.len() * other.elements.len());
+        for a in &self.elements {
+            for b in &other.elements {
+                result.push((a.clone(), b.clone()));
+            }
+        }
+        Set { elements: result }
+    }
+
     #[inline]
     pub fn len(&self) -> usize {
         self.elements.len()
@@ -134,38 +155,38 @@ impl Set {
         self.len() == 0
     }
 
-    pub fn index_of(&self, element: char) -> Option<usize> {
+    pub fn index_of(&self, element: T) -> Option<usize> {
         self.elements.binary_search(&element).ok()
     }
 
     #[inline]
-    pub fn contains(&self, element: char) -> bool {
+    pub fn contains(&self, element: T) -> bool {
         self.index_of(element).is_some()
     }
 }
 
-impl std::ops::Add<&Set> for &Set {
-    type Output = Set;
-    fn add(self, other: &Set) -> Set {
+impl<T: Ord + Clone> std::ops::Add<&Set<T>> for &Set<T> {
+    type Output = Set<T>;
+    fn add(self, other: &Set<T>) -> Set<T> {
         self.union(other)
     }
 }
 
-impl std::ops::BitAnd<&Set> for &Set {
-    type Output = Set;
-    fn bitand(self, other: &Set) -> Set {
+impl<T: Ord + Clone> std::ops::BitAnd<&Set<T>> for &Set<T> {
+    type Output = Set<T>;
+    fn bitand(self, other: &Set<T>) -> Set<T> {
         self.intersection(other)
     }
 }
 
-impl std::ops::Sub<&Set> for &Set {
-    type Output = Set;
-    fn sub(self, other: &Set) -> Set {
+impl<T: Ord + Clone> std::ops::Sub<&Set<T>> for &Set<T> {
+    type Output = Set<T>;
+    fn sub(self, other: &Set<T>) -> Set<T> {
         self.difference(other)
     }
 }
 
-impl fmt::Display for Set {
+impl<T: fmt::Display> fmt::Display for Set<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = format!(
             "{{{}}}",
@@ -179,35 +200,35 @@ impl fmt::Display for Set {
     }
 }
 
-impl<'a> IntoIterator for &'a Set {
-    type Item = &'a char;
-    type IntoIter = std::slice::Iter<'a, char>;
+impl<'a, T> IntoIterator for &'a Set<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.elements.iter()
     }
 }
 
-impl<'a> IntoIterator for &'a mut Set {
-    type Item = &'a mut char;
-    type IntoIter = std::slice::IterMut<'a, char>;
+impl<'a, T> IntoIterator for &'a mut Set<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.elements.iter_mut()
     }
 }
 
-impl IntoIterator for Set {
-    type Item = char;
-    type IntoIter = std::vec::IntoIter<char>;
+impl<T> IntoIterator for Set<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.elements.into_iter()
     }
 }
 
-impl Set {
-    pub fn iter(&self) -> std::slice::Iter<'_, char> {
+impl<T> Set<T> {
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
         self.elements.iter()
     }
 }
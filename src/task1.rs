@@ -1,7 +1,18 @@
 use crate::math::Set;
+use crate::task3::repl_helpers::{paint, COMMAND, OPERATOR};
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{self, BufRead};
+use std::rc::Rc;
+
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Context, Editor, Helper};
 
 fn find_set(sets: &mut HashMap<char, Set>, name: char) -> Option<&mut Set> {
     sets.get_mut(&name)
@@ -126,6 +137,123 @@ fn handle_command(sets: &mut HashMap<char, Set>, line: &str) -> Result<(), Strin
     Ok(())
 }
 
+const COMMANDS: &[&str] = &["new", "del", "add", "rem", "pow", "see"];
+const OPERATORS: &[char] = &['+', '&', '-', '<', '='];
+const HISTORY_FILE: &str = ".t1_repl_history";
+
+// rustyline helper over the live set table: completes command verbs and the
+// currently-defined single-character set names, colours operators and verbs,
+// and refuses a binary operation whose second operand is still missing.
+struct SetHelper {
+    sets: Rc<RefCell<HashMap<char, Set>>>,
+}
+
+impl Completer for SetHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let start = line[..pos]
+            .rfind(char::is_whitespace)
+            .map_or(0, |i| i + 1);
+        let word = &line[start..pos];
+
+        let mut candidates: Vec<String> = COMMANDS
+            .iter()
+            .filter(|c| c.starts_with(word))
+            .map(|c| c.to_string())
+            .collect();
+        for name in self.sets.borrow().keys() {
+            let name = name.to_string();
+            if name.starts_with(word) {
+                candidates.push(name);
+            }
+        }
+        Ok((start, candidates))
+    }
+}
+
+impl Highlighter for SetHelper {
+    fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        let mut out = String::with_capacity(line.len());
+        for token in line.split_inclusive(char::is_whitespace) {
+            let trimmed = token.trim_end();
+            if trimmed.len() == 1 && OPERATORS.contains(&trimmed.chars().next().unwrap()) {
+                out.push_str(&paint(trimmed, OPERATOR));
+                out.push_str(&token[trimmed.len()..]);
+            } else if COMMANDS.contains(&trimmed) {
+                out.push_str(&paint(trimmed, COMMAND));
+                out.push_str(&token[trimmed.len()..]);
+            } else {
+                out.push_str(token);
+            }
+        }
+        Cow::Owned(out)
+    }
+
+    fn highlight_char(&self, _line: &str, _pos: usize, _forced: bool) -> bool {
+        true
+    }
+}
+
+impl Validator for SetHelper {
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        let parts: Vec<&str> = ctx.input().split_whitespace().collect();
+        // `A op` with the right operand still missing — let the user keep typing.
+        if parts.len() == 2 {
+            if let Some(op) = parts[1].chars().next() {
+                if parts[1].len() == 1 && OPERATORS.contains(&op) {
+                    return Ok(ValidationResult::Incomplete);
+                }
+            }
+        }
+        Ok(ValidationResult::Valid(None))
+    }
+}
+
+impl Hinter for SetHelper {
+    type Hint = String;
+}
+
+impl Helper for SetHelper {}
+
+pub fn run_task_1_repl() -> Result<(), String> {
+    let sets: Rc<RefCell<HashMap<char, Set>>> = Rc::new(RefCell::new(HashMap::new()));
+
+    let mut rl: Editor<SetHelper, _> =
+        Editor::new().map_err(|e| format!("Failed to start line editor: {}", e))?;
+    rl.set_helper(Some(SetHelper { sets: sets.clone() }));
+    let _ = rl.load_history(HISTORY_FILE);
+
+    println!("Interactive set calculator. Commands: new/del/add/rem/pow/see and");
+    println!("binary ops 'A + B', 'A & B', 'A - B', 'A < B', 'A = B' (Ctrl-D to quit).");
+
+    loop {
+        match rl.readline("set> ") {
+            Ok(line) => {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let _ = rl.add_history_entry(line);
+                let mut table = sets.borrow_mut();
+                if let Err(e) = handle_command(&mut table, line) {
+                    println!("error: {}", e);
+                }
+            }
+            Err(ReadlineError::Eof) | Err(ReadlineError::Interrupted) => break,
+            Err(e) => return Err(format!("Line editor error: {}", e)),
+        }
+    }
+
+    let _ = rl.save_history(HISTORY_FILE);
+    Ok(())
+}
+
 pub fn run_task_1(file_path: &str) -> Result<(), String> {
     println!("Running task 1 on {}", file_path);
 
@@ -14,6 +14,59 @@ pub fn collect_vars(ast: &Ast, set: &mut BTreeSet<String>) {
     }
 }
 
+/// Collect variable names in first-appearance (left-to-right) order, which is
+/// the natural ordering for a hand-typed formula.
+pub fn collect_vars_in_order(ast: &Ast, out: &mut Vec<String>) {
+    match ast {
+        Ast::Var(v) => {
+            if !out.contains(v) {
+                out.push(v.clone());
+            }
+        }
+        Ast::Not(x) => collect_vars_in_order(x, out),
+        Ast::BinOp(_, l, r) => {
+            collect_vars_in_order(l, out);
+            collect_vars_in_order(r, out);
+        }
+    }
+}
+
+/// Evaluate `ast` on a single row encoded as `mask`, where variable `i` takes
+/// bit `n - 1 - i` of the mask (MSB-first, matching the table ordering).
+pub fn eval_ast_mask(ast: &Ast, vars: &[String], mask: usize) -> u8 {
+    let n = vars.len();
+    match ast {
+        Ast::Var(v) => match vars.iter().position(|name| name == v) {
+            Some(i) => ((mask >> (n - 1 - i)) & 1) as u8,
+            None => 0,
+        },
+        Ast::Not(x) => 1 - eval_ast_mask(x, vars, mask),
+        Ast::BinOp(op, l, r) => {
+            let a = eval_ast_mask(l, vars, mask) != 0;
+            let b = eval_ast_mask(r, vars, mask) != 0;
+            let v = match op {
+                super::ast::BinOp::Or => a | b,
+                super::ast::BinOp::And => a & b,
+                super::ast::BinOp::Xor => a ^ b,
+                super::ast::BinOp::Equiv => a == b,
+                super::ast::BinOp::Impl => (!a) | b,
+                super::ast::BinOp::Nand => !(a & b),
+                super::ast::BinOp::Nor => !(a | b),
+            };
+            v as u8
+        }
+    }
+}
+
+/// Collect the formula's variables in first-appearance order and build its
+/// truth table, ready to feed `sdnf_from_truth` / `sknf_from_truth` / `anf`.
+pub fn truth_table(ast: &Ast) -> (Vec<String>, Vec<u8>) {
+    let mut vars = Vec::new();
+    collect_vars_in_order(ast, &mut vars);
+    let table = truth_table_from_ast(ast, &vars);
+    (vars, table)
+}
+
 pub fn eval_ast(ast: &Ast, env: &BTreeMap<String, bool>) -> bool {
     match ast {
         Ast::Var(v) => *env.get(v).unwrap_or(&false),
@@ -36,18 +89,81 @@ pub fn eval_ast(ast: &Ast, env: &BTreeMap<String, bool>) -> bool {
 
 pub fn truth_table_from_ast(ast: &Ast, vars: &[String]) -> Vec<u8> {
     let n = vars.len();
-    let size = 1 << n;
-    let mut out = vec![0u8; size];
-    let mut env = BTreeMap::new();
-    for v in vars.iter() {
-        env.insert(v.clone(), false);
+    let size = 1usize << n;
+    let columns = eval_columns(ast, vars);
+
+    // Unpack the final bitset into the byte table the rest of the code expects.
+    (0..size)
+        .map(|r| ((columns[r / 64] >> (r % 64)) & 1) as u8)
+        .collect()
+}
+
+// Bit-parallel evaluator: every intermediate result is a column vector of
+// `ceil(2^n / 64)` words where bit `r` holds the subexpression's value on row
+// `r` (MSB-first row ordering, matching the rest of the module). A whole table
+// is computed in a single AST traversal with word-wise operations, turning
+// per-row interpretation into O(nodes * 2^n / 64).
+fn eval_columns(ast: &Ast, vars: &[String]) -> Vec<u64> {
+    let n = vars.len();
+    let size = 1usize << n;
+    let words = size.div_ceil(64);
+
+    match ast {
+        Ast::Var(v) => {
+            let i = vars.iter().position(|name| name == v);
+            let mut col = vec![0u64; words];
+            if let Some(i) = i {
+                // Variable `i` is 1 on row `r` iff (r >> (n-1-i)) & 1 == 1.
+                for r in 0..size {
+                    if (r >> (n - 1 - i)) & 1 == 1 {
+                        col[r / 64] |= 1u64 << (r % 64);
+                    }
+                }
+            }
+            col
+        }
+        Ast::Not(x) => {
+            let mut col = eval_columns(x, vars);
+            complement(&mut col, size);
+            col
+        }
+        Ast::BinOp(op, l, r) => {
+            let a = eval_columns(l, vars);
+            let b = eval_columns(r, vars);
+            let mut out = vec![0u64; words];
+            for w in 0..words {
+                out[w] = match op {
+                    super::ast::BinOp::Or => a[w] | b[w],
+                    super::ast::BinOp::And => a[w] & b[w],
+                    super::ast::BinOp::Xor => a[w] ^ b[w],
+                    super::ast::BinOp::Equiv => !(a[w] ^ b[w]),
+                    super::ast::BinOp::Impl => !a[w] | b[w],
+                    super::ast::BinOp::Nand => !(a[w] & b[w]),
+                    super::ast::BinOp::Nor => !(a[w] | b[w]),
+                };
+            }
+            // `!`, Equiv, Impl, Nand and Nor set bits above `size`; clear them so
+            // the unused high bits of the final word stay zero.
+            mask_unused(&mut out, size);
+            out
+        }
+    }
+}
+
+// Bitwise-complement every word, then drop the bits past `size` in the final
+// word so only meaningful rows remain set.
+fn complement(col: &mut [u64], size: usize) {
+    for w in col.iter_mut() {
+        *w = !*w;
+    }
+    mask_unused(col, size);
+}
+
+fn mask_unused(col: &mut [u64], size: usize) {
+    let rem = size % 64;
+    if rem != 0 {
+        if let Some(last) = col.last_mut() {
+            *last &= (1u64 << rem) - 1;
+        }
     }
-    (0..size).for_each(|mask| {
-        (0..n).for_each(|i| {
-            let bit = ((mask >> (n - 1 - i)) & 1) != 0;
-            env.insert(vars[i].clone(), bit);
-        });
-        out[mask] = if eval_ast(ast, &env) { 1 } else { 0 };
-    });
-    out
 }
@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 fn minterm_str(vars: &[String], bits: usize, mask: usize) -> String {
     let mut parts = Vec::new();
     (0..bits).for_each(|i| {
@@ -133,6 +135,212 @@ pub fn dual_from_truth(table: &[u8], nvars: usize) -> Vec<u8> {
     out
 }
 
+// An implicant as a `(value, dashes)` pair over the row-index bit space, where
+// a set bit in `dashes` marks a don't-care position.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Implicant {
+    value: usize,
+    dashes: usize,
+}
+
+impl Implicant {
+    fn covers(&self, minterm: usize) -> bool {
+        (minterm & !self.dashes) == (self.value & !self.dashes)
+    }
+}
+
+fn implicant_str(vars: &[String], bits: usize, imp: &Implicant) -> String {
+    let mut parts = Vec::new();
+    (0..bits).for_each(|i| {
+        let p = bits - 1 - i;
+        if (imp.dashes >> p) & 1 == 1 {
+            return;
+        }
+        let set = ((imp.value >> p) & 1) != 0;
+        parts.push(if set {
+            vars[i].clone()
+        } else {
+            format!("-{}", vars[i])
+        });
+    });
+    if parts.is_empty() {
+        "1".to_string()
+    } else {
+        parts.join(" & ")
+    }
+}
+
+// Generate all prime implicants of a set of minterms by repeatedly combining
+// adjacent terms that differ in a single non-dash bit.
+fn prime_implicants(minterms: &[usize]) -> Vec<Implicant> {
+    let mut current: Vec<Implicant> = minterms
+        .iter()
+        .map(|&m| Implicant {
+            value: m,
+            dashes: 0,
+        })
+        .collect();
+    let mut primes: Vec<Implicant> = Vec::new();
+
+    while !current.is_empty() {
+        let mut used = vec![false; current.len()];
+        let mut next: Vec<Implicant> = Vec::new();
+
+        for a in 0..current.len() {
+            for b in (a + 1)..current.len() {
+                if current[a].dashes != current[b].dashes {
+                    continue;
+                }
+                let diff = current[a].value ^ current[b].value;
+                if diff.count_ones() == 1 {
+                    used[a] = true;
+                    used[b] = true;
+                    let combined = Implicant {
+                        value: current[a].value & !diff,
+                        dashes: current[a].dashes | diff,
+                    };
+                    if !next.contains(&combined) {
+                        next.push(combined);
+                    }
+                }
+            }
+        }
+
+        for (i, imp) in current.iter().enumerate() {
+            if !used[i] && !primes.contains(imp) {
+                primes.push(*imp);
+            }
+        }
+        current = next;
+    }
+
+    primes
+}
+
+fn literal_count(imp: &Implicant, bits: usize) -> usize {
+    bits - (imp.dashes.count_ones() as usize).min(bits)
+}
+
+// Pick essential prime implicants, then cover any remaining minterms exactly
+// with Petrick's method (fewest total literals wins).
+fn select_cover(primes: &[Implicant], minterms: &[usize], bits: usize) -> Vec<Implicant> {
+    let mut chosen: Vec<usize> = Vec::new();
+    let mut uncovered: BTreeSet<usize> = minterms.iter().copied().collect();
+
+    for &m in minterms {
+        let covering: Vec<usize> = (0..primes.len()).filter(|&p| primes[p].covers(m)).collect();
+        if covering.len() == 1 && !chosen.contains(&covering[0]) {
+            chosen.push(covering[0]);
+        }
+    }
+    for &p in &chosen {
+        uncovered.retain(|&m| !primes[p].covers(m));
+    }
+
+    if !uncovered.is_empty() {
+        // Petrick's method: a product of sums over the remaining columns,
+        // multiplied out to a sum of products with absorption.
+        let mut products: Vec<BTreeSet<usize>> = vec![BTreeSet::new()];
+        for &m in &uncovered {
+            let clause: Vec<usize> = (0..primes.len()).filter(|&p| primes[p].covers(m)).collect();
+            let mut next: Vec<BTreeSet<usize>> = Vec::new();
+            for prod in &products {
+                for &p in &clause {
+                    let mut np = prod.clone();
+                    np.insert(p);
+                    next.push(np);
+                }
+            }
+            // Absorption: drop any product that is a superset of another.
+            next.sort_by_key(|s| s.len());
+            let mut reduced: Vec<BTreeSet<usize>> = Vec::new();
+            for cand in next {
+                if !reduced.iter().any(|r| r.is_subset(&cand)) {
+                    reduced.push(cand);
+                }
+            }
+            products = reduced;
+        }
+
+        let best = products.into_iter().min_by_key(|prod| {
+            prod.iter().map(|&p| literal_count(&primes[p], bits)).sum::<usize>()
+        });
+        if let Some(best) = best {
+            for p in best {
+                if !chosen.contains(&p) {
+                    chosen.push(p);
+                }
+            }
+        }
+    }
+
+    chosen.into_iter().map(|p| primes[p]).collect()
+}
+
+/// Minimal DNF via Quine-McCluskey with exact (Petrick) covering.
+pub fn minimize_dnf(vars: &[String], table: &[u8]) -> String {
+    let n = vars.len();
+    let size = 1usize << n;
+    let minterms: Vec<usize> = (0..size).filter(|&m| table[m] == 1).collect();
+    if minterms.is_empty() {
+        return "0".to_string();
+    }
+    if minterms.len() == size {
+        return "1".to_string();
+    }
+
+    let primes = prime_implicants(&minterms);
+    select_cover(&primes, &minterms, n)
+        .iter()
+        .map(|imp| format!("({})", implicant_str(vars, n, imp)))
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+/// Minimal CNF: minimize over the zero rows and render each implicant as a
+/// disjunctive clause (the dual of [`minimize_dnf`]).
+pub fn minimize_cnf(vars: &[String], table: &[u8]) -> String {
+    let n = vars.len();
+    let size = 1usize << n;
+    let maxterms: Vec<usize> = (0..size).filter(|&m| table[m] == 0).collect();
+    if maxterms.is_empty() {
+        return "1".to_string();
+    }
+    if maxterms.len() == size {
+        return "0".to_string();
+    }
+
+    let primes = prime_implicants(&maxterms);
+    select_cover(&primes, &maxterms, n)
+        .iter()
+        .map(|imp| format!("({})", clause_str(vars, n, imp)))
+        .collect::<Vec<_>>()
+        .join(" & ")
+}
+
+// Render an implicant over the zero-set as a disjunctive clause: a fixed 1-bit
+// becomes a negated literal, a fixed 0-bit a plain one.
+fn clause_str(vars: &[String], bits: usize, imp: &Implicant) -> String {
+    let mut parts = Vec::new();
+    (0..bits).for_each(|i| {
+        let p = bits - 1 - i;
+        if (imp.dashes >> p) & 1 == 1 {
+            return;
+        }
+        let set = ((imp.value >> p) & 1) != 0;
+        parts.push(if set {
+            format!("-{}", vars[i])
+        } else {
+            vars[i].clone()
+        });
+    });
+    if parts.is_empty() {
+        "0".to_string()
+    } else {
+        parts.join(" + ")
+    }
+}
+
 pub fn sdnf_from_truth(vars: &[String], table: &[u8]) -> String {
     let n = vars.len();
     let size = 1 << n;
@@ -166,3 +374,34 @@ pub fn sknf_from_truth(vars: &[String], table: &[u8]) -> String {
         clauses.join(" & ")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn minimize_dnf_drops_fictitious_variable() {
+        // f(a, b) = a (b is don't-care) reduces to the single literal.
+        let v = vars(&["a", "b"]);
+        assert_eq!(minimize_dnf(&v, &[0, 0, 1, 1]), "(a)");
+        assert_eq!(minimize_cnf(&v, &[0, 0, 1, 1]), "(a)");
+    }
+
+    #[test]
+    fn minimize_dnf_xor_is_irreducible() {
+        // a ⊕ b has no adjacent minterms, so both stay as full products.
+        let v = vars(&["a", "b"]);
+        assert_eq!(minimize_dnf(&v, &[0, 1, 1, 0]), "(-a & b) + (a & -b)");
+    }
+
+    #[test]
+    fn minimize_constants() {
+        let v = vars(&["a", "b"]);
+        assert_eq!(minimize_dnf(&v, &[0, 0, 0, 0]), "0");
+        assert_eq!(minimize_dnf(&v, &[1, 1, 1, 1]), "1");
+    }
+}
@@ -2,22 +2,31 @@ pub mod ast;
 pub mod eval;
 pub mod forms;
 pub mod parser;
+pub mod post;
+pub mod repl;
+pub mod repl_helpers;
 
 pub use ast::{Ast, BinOp};
-pub use eval::{collect_vars, eval_ast, truth_table_from_ast};
+pub use eval::{
+    collect_vars, collect_vars_in_order, eval_ast, eval_ast_mask, truth_table,
+    truth_table_from_ast,
+};
 pub use forms::{
-    anf_from_truth, anf_to_str, dual_from_truth, find_fictitious, remove_fictitious,
-    sdnf_from_truth, sknf_from_truth,
+    anf_from_truth, anf_to_str, dual_from_truth, find_fictitious, minimize_cnf, minimize_dnf,
+    remove_fictitious, sdnf_from_truth, sknf_from_truth,
 };
-pub use parser::{parse_expr, tokenize};
+pub use parser::{parse_expr, parse_formula, tokenize, ParseError};
+pub use post::{blocking_classes, is_complete, post_classes, PostClasses};
+pub use repl::run_repl;
 
 pub fn run_task_3(file_path: &str) -> Result<(), String> {
     use eval::{collect_vars, truth_table_from_ast};
     use forms::{
-        anf_from_truth, anf_to_str, dual_from_truth, find_fictitious, remove_fictitious,
-        sdnf_from_truth, sknf_from_truth,
+        anf_from_truth, anf_to_str, dual_from_truth, find_fictitious, minimize_cnf, minimize_dnf,
+        remove_fictitious, sdnf_from_truth, sknf_from_truth,
     };
     use parser::{parse_expr, tokenize};
+    use post::post_classes;
     use std::fs;
 
     let s = fs::read_to_string(file_path)
@@ -102,9 +111,13 @@ pub fn run_task_3(file_path: &str) -> Result<(), String> {
         let dual = dual_from_truth(&new_table, n_new);
         let sdnf = sdnf_from_truth(&new_vars, &new_table);
         let sknf = sknf_from_truth(&new_vars, &new_table);
+        let min_dnf = minimize_dnf(&new_vars, &new_table);
+        let min_cnf = minimize_cnf(&new_vars, &new_table);
         let anf_coefs = anf_from_truth(&new_table, n_new);
         let anf = anf_to_str(&anf_coefs, &new_vars);
 
+        let classes = post_classes(&new_table, n_new);
+
         let sdnf_dual = sdnf_from_truth(&new_vars, &dual);
         let sknf_dual = sknf_from_truth(&new_vars, &dual);
 
@@ -112,7 +125,10 @@ pub fn run_task_3(file_path: &str) -> Result<(), String> {
         println!("Variables: {:?}", new_vars);
         println!("SDNF: {}", sdnf);
         println!("SKNF: {}", sknf);
+        println!("Minimal DNF: {}", min_dnf);
+        println!("Minimal CNF: {}", min_cnf);
         println!("ANF: {}", anf);
+        println!("Post classes: {}", classes.row_str());
         println!("Dual function truth table:");
         for (i, &v) in dual.iter().enumerate() {
             println!("{:0width$b} -> {}", i, v, width = n_new);
@@ -1,4 +1,29 @@
 use super::ast::{Ast, BinOp};
+use thiserror::Error;
+
+/// A failure while turning a textual formula into an [`Ast`].
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("tokenization failed: {0}")]
+    Tokenize(String),
+
+    #[error("parse failed: {0}")]
+    Parse(String),
+
+    #[error("unexpected trailing tokens after expression")]
+    TrailingTokens,
+}
+
+/// Tokenize and parse a whole formula, rejecting anything left over, so a
+/// string can be dropped straight into the truth-table pipeline.
+pub fn parse_formula(s: &str) -> Result<Ast, ParseError> {
+    let tokens = tokenize(s).map_err(ParseError::Tokenize)?;
+    let (ast, pos) = parse_expr(&tokens).map_err(ParseError::Parse)?;
+    if pos != tokens.len() {
+        return Err(ParseError::TrailingTokens);
+    }
+    Ok(ast)
+}
 
 #[derive(Debug, Clone)]
 pub enum Token {
@@ -77,45 +102,75 @@ pub fn tokenize(s: &str) -> Result<Vec<Token>, String> {
 }
 
 pub fn parse_expr(tokens: &[Token]) -> Result<(Ast, usize), String> {
-    parse_at(tokens, 0)
+    parse_binary(tokens, 0, 0)
+}
+
+// Binding powers `(left, right)` for each binary connective, tightest first:
+// And, then Xor, then Or, then Nand/Nor, then Impl (right-associative), then
+// Equiv at the lowest level. Left-associative operators have `right = left + 1`
+// so equal-precedence operators fold leftwards; Impl flips this to associate
+// rightwards. Unary `-` (Not) binds tighter than any of them and is handled in
+// `parse_unary`; `!` is always the binary Nor.
+fn binding_power(op: char) -> Option<(u8, u8)> {
+    let bp = match BinOp::from_char(op)? {
+        BinOp::And => (12, 13),
+        BinOp::Xor => (10, 11),
+        BinOp::Or => (8, 9),
+        BinOp::Nand | BinOp::Nor => (6, 7),
+        BinOp::Impl => (5, 4),
+        BinOp::Equiv => (2, 3),
+    };
+    Some(bp)
+}
+
+// Precedence-climbing core: parse an atom, then keep folding in binary
+// operators whose left binding power is at least `min_bp`, recursing with the
+// operator's right binding power to capture its right operand.
+fn parse_binary(tokens: &[Token], pos: usize, min_bp: u8) -> Result<(Ast, usize), String> {
+    let (mut lhs, mut pos) = parse_unary(tokens, pos)?;
+
+    while pos < tokens.len() {
+        let op = match &tokens[pos] {
+            Token::Op(c) => *c,
+            // `)` ends the current (sub)expression; anything else is a dangling
+            // atom that the caller will reject as trailing tokens.
+            _ => break,
+        };
+
+        let (l_bp, r_bp) = match binding_power(op) {
+            Some(bp) => bp,
+            None => return Err(format!("Unknown binary operator '{}'", op)),
+        };
+        if l_bp < min_bp {
+            break;
+        }
+
+        let (rhs, next) = parse_binary(tokens, pos + 1, r_bp)?;
+        lhs = Ast::BinOp(BinOp::from_char(op).unwrap(), Box::new(lhs), Box::new(rhs));
+        pos = next;
+    }
+
+    Ok((lhs, pos))
 }
 
-fn parse_at(tokens: &[Token], pos: usize) -> Result<(Ast, usize), String> {
+// Leading `-` (Not) and atoms: a variable or a parenthesized sub-expression.
+// Redundant parentheses are accepted, so the old fully-parenthesized syntax
+// still parses as a subset of the grammar.
+fn parse_unary(tokens: &[Token], pos: usize) -> Result<(Ast, usize), String> {
     if pos >= tokens.len() {
         return Err("Unexpected end of tokens".to_string());
     }
     match &tokens[pos] {
         Token::Var(name) => Ok((Ast::Var(name.clone()), pos + 1)),
         Token::Op('-') => {
-            let (sub, np) = parse_at(tokens, pos + 1)?;
+            let (sub, np) = parse_unary(tokens, pos + 1)?;
             Ok((Ast::Not(Box::new(sub)), np))
         }
         Token::LParen => {
-            let (left, p1) = parse_at(tokens, pos + 1)?;
-            if p1 >= tokens.len() {
-                return Err("Unexpected end, expected operator after left expr".to_string());
-            }
-            let op = match &tokens[p1] {
-                Token::Op(c) => *c,
-                _ => return Err("Expected binary operator after left expression".to_string()),
-            };
-            if BinOp::from_char(op).is_none() {
-                return Err(format!("Unknown binary operator '{}'", op));
-            }
-            let (right, p2) = parse_at(tokens, p1 + 1)?;
-            if p2 >= tokens.len() {
-                return Err("Unexpected end, expected ')'".to_string());
-            }
-            match &tokens[p2] {
-                Token::RParen => Ok((
-                    Ast::BinOp(
-                        BinOp::from_char(op).unwrap(),
-                        Box::new(left),
-                        Box::new(right),
-                    ),
-                    p2 + 1,
-                )),
-                _ => Err("Expected closing ')' after binary expression".to_string()),
+            let (inner, np) = parse_binary(tokens, pos + 1, 0)?;
+            match tokens.get(np) {
+                Some(Token::RParen) => Ok((inner, np + 1)),
+                _ => Err("Expected closing ')' after expression".to_string()),
             }
         }
         Token::Op(c) => Err(format!("Unexpected operator token '{}'", c)),
@@ -0,0 +1,101 @@
+use super::forms::{anf_from_truth, dual_from_truth};
+
+/// Membership of a boolean function in the five Post classes.
+#[derive(Debug, Clone, Copy)]
+pub struct PostClasses {
+    /// Preserves zero: `f(0, …, 0) = 0`.
+    pub t0: bool,
+    /// Preserves one: `f(1, …, 1) = 1`.
+    pub t1: bool,
+    /// Self-dual.
+    pub s: bool,
+    /// Monotone.
+    pub m: bool,
+    /// Linear (affine over GF(2)).
+    pub l: bool,
+}
+
+impl PostClasses {
+    /// A `T0 T1 S M L` row of `+`/`-` flags, matching the relation-properties
+    /// style used by `run_task_2`.
+    pub fn row_str(&self) -> String {
+        let flag = |b: bool| if b { "+" } else { "-" };
+        format!(
+            "T0:{} T1:{} S:{} M:{} L:{}",
+            flag(self.t0),
+            flag(self.t1),
+            flag(self.s),
+            flag(self.m),
+            flag(self.l)
+        )
+    }
+}
+
+/// Classify a single function given its truth table and variable count.
+pub fn post_classes(table: &[u8], nvars: usize) -> PostClasses {
+    let size = 1usize << nvars;
+
+    let t0 = table[0] == 0;
+    let t1 = table[size - 1] == 1;
+    let s = dual_from_truth(table, nvars) == table;
+
+    // Monotone: whenever assignment `x` is bitwise-dominated by `y`, the value
+    // must not drop.
+    let mut m = true;
+    'outer: for x in 0..size {
+        for y in 0..size {
+            if x & y == x && table[x] > table[y] {
+                m = false;
+                break 'outer;
+            }
+        }
+    }
+
+    // Linear: the Zhegalkin coefficients are nonzero only for the constant term
+    // and single-variable terms (no products).
+    let coefs = anf_from_truth(table, nvars);
+    let l = (0..size).all(|mask| coefs[mask] == 0 || mask == 0 || mask.count_ones() == 1);
+
+    PostClasses { t0, t1, s, m, l }
+}
+
+/// By Post's theorem a system of functions is functionally complete iff, for
+/// each of the five classes, at least one function lies outside it.
+pub fn is_complete(functions: &[&[u8]]) -> bool {
+    blocking_classes(functions).is_empty()
+}
+
+/// Classes that every function in the system belongs to, and therefore block
+/// completeness. An empty result means the system is complete.
+pub fn blocking_classes(functions: &[&[u8]]) -> Vec<&'static str> {
+    if functions.is_empty() {
+        return vec!["T0", "T1", "S", "M", "L"];
+    }
+
+    let classes: Vec<PostClasses> = functions
+        .iter()
+        .map(|table| post_classes(table, nvars_of(table.len())))
+        .collect();
+
+    let mut blocking = Vec::new();
+    if classes.iter().all(|c| c.t0) {
+        blocking.push("T0");
+    }
+    if classes.iter().all(|c| c.t1) {
+        blocking.push("T1");
+    }
+    if classes.iter().all(|c| c.s) {
+        blocking.push("S");
+    }
+    if classes.iter().all(|c| c.m) {
+        blocking.push("M");
+    }
+    if classes.iter().all(|c| c.l) {
+        blocking.push("L");
+    }
+    blocking
+}
+
+fn nvars_of(size: usize) -> usize {
+    size.max(1).trailing_zeros() as usize
+}
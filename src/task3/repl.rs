@@ -0,0 +1,196 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Editor, Helper};
+
+use super::eval::{collect_vars, truth_table_from_ast};
+use super::forms::{anf_from_truth, anf_to_str, dual_from_truth, sdnf_from_truth, sknf_from_truth};
+use super::parser::{parse_expr, tokenize, Token};
+use super::repl_helpers::{brackets_balanced, paint, OPERATOR, VARIABLE};
+
+const HISTORY_FILE: &str = ".t3_repl_history";
+
+// rustyline helper: live highlighting of operator versus variable tokens and a
+// validator that keeps a line "incomplete" until its parentheses balance, so a
+// formula can be typed across several lines.
+struct ReplHelper;
+
+impl Highlighter for ReplHelper {
+    fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        let mut out = String::with_capacity(line.len());
+        for ch in line.chars() {
+            match ch {
+                '+' | '&' | '@' | '~' | '>' | '|' | '!' | '-' => {
+                    out.push_str(&paint(&ch.to_string(), OPERATOR));
+                }
+                c if c.is_ascii_alphanumeric() || c == '_' => {
+                    out.push_str(&paint(&c.to_string(), VARIABLE));
+                }
+                c => out.push(c),
+            }
+        }
+        Cow::Owned(out)
+    }
+
+    fn highlight_char(&self, _line: &str, _pos: usize, _forced: bool) -> bool {
+        true
+    }
+}
+
+impl Validator for ReplHelper {
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        let input = ctx.input();
+        if input.trim_start().starts_with(':') {
+            return Ok(ValidationResult::Valid(None));
+        }
+        if brackets_balanced(input) {
+            Ok(ValidationResult::Valid(None))
+        } else {
+            Ok(ValidationResult::Incomplete)
+        }
+    }
+}
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Helper for ReplHelper {}
+
+// The most recently entered formula, kept so the meta-commands can re-run a
+// specific analysis without re-typing it.
+#[derive(Default)]
+struct Last {
+    vars: Vec<String>,
+    table: Vec<u8>,
+}
+
+pub fn run_repl() -> Result<(), String> {
+    let mut rl: Editor<ReplHelper, _> =
+        Editor::new().map_err(|e| format!("Failed to start line editor: {}", e))?;
+    rl.set_helper(Some(ReplHelper));
+    let _ = rl.load_history(HISTORY_FILE);
+
+    println!("Interactive formula REPL. Type a formula, or a meta-command:");
+    println!("  :vars  :table  :sdnf  :props  :load <file>  (Ctrl-D to quit)");
+
+    let mut last = Last::default();
+
+    loop {
+        match rl.readline("t3> ") {
+            Ok(line) => {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let _ = rl.add_history_entry(line);
+
+                if let Some(rest) = line.strip_prefix(':') {
+                    if !handle_meta(rest.trim(), &mut last) {
+                        break;
+                    }
+                } else {
+                    match analyze(line) {
+                        Ok((vars, table)) => {
+                            print_table(&vars, &table);
+                            last = Last { vars, table };
+                        }
+                        Err(e) => println!("error: {}", e),
+                    }
+                }
+            }
+            Err(ReadlineError::Eof) | Err(ReadlineError::Interrupted) => break,
+            Err(e) => return Err(format!("Line editor error: {}", e)),
+        }
+    }
+
+    let _ = rl.save_history(HISTORY_FILE);
+    Ok(())
+}
+
+// Returns `false` when the REPL should exit.
+fn handle_meta(cmd: &str, last: &mut Last) -> bool {
+    let mut parts = cmd.splitn(2, char::is_whitespace);
+    let head = parts.next().unwrap_or("");
+    let arg = parts.next().unwrap_or("").trim();
+
+    match head {
+        "quit" | "exit" | "q" => return false,
+        "load" => {
+            if arg.is_empty() {
+                println!("usage: :load <file>");
+            } else {
+                load_batch(arg, last);
+            }
+        }
+        "vars" => println!("Variables: {:?}", last.vars),
+        "table" => print_table(&last.vars, &last.table),
+        "sdnf" => println!("SDNF: {}", sdnf_from_truth(&last.vars, &last.table)),
+        "props" => print_props(&last.vars, &last.table),
+        other => println!("unknown command ':{}'", other),
+    }
+    true
+}
+
+fn load_batch(path: &str, last: &mut Last) {
+    let text = match std::fs::read_to_string(path) {
+        Ok(t) => t,
+        Err(e) => {
+            println!("error: cannot read '{}': {}", path, e);
+            return;
+        }
+    };
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        println!("t3< {}", line);
+        match analyze(line) {
+            Ok((vars, table)) => {
+                print_table(&vars, &table);
+                *last = Last { vars, table };
+            }
+            Err(e) => println!("error: {}", e),
+        }
+    }
+}
+
+fn analyze(formula: &str) -> Result<(Vec<String>, Vec<u8>), String> {
+    let tokens: Vec<Token> = tokenize(formula)?;
+    let (ast, pos) = parse_expr(&tokens)?;
+    if pos != tokens.len() {
+        return Err("extra tokens after full parse".to_string());
+    }
+    let mut varset = BTreeSet::new();
+    collect_vars(&ast, &mut varset);
+    let vars: Vec<String> = varset.into_iter().collect();
+    let table = truth_table_from_ast(&ast, &vars);
+    Ok((vars, table))
+}
+
+fn print_table(vars: &[String], table: &[u8]) {
+    if vars.is_empty() {
+        println!("constant function = {}", table.first().copied().unwrap_or(0));
+        return;
+    }
+    println!("Variables: {:?}", vars);
+    for (i, &v) in table.iter().enumerate() {
+        println!("{:0width$b} -> {}", i, v, width = vars.len());
+    }
+}
+
+fn print_props(vars: &[String], table: &[u8]) {
+    let n = vars.len();
+    println!("SDNF: {}", sdnf_from_truth(vars, table));
+    println!("SKNF: {}", sknf_from_truth(vars, table));
+    let anf = anf_to_str(&anf_from_truth(table, n), vars);
+    println!("ANF: {}", anf);
+    let dual = dual_from_truth(table, n);
+    println!("Self-dual: {}", if dual == table { "+" } else { "-" });
+}
@@ -0,0 +1,30 @@
+//! Small pieces shared by the interactive REPLs (the set calculator in `task1`
+//! and the formula analyser here): ANSI colouring of highlighted tokens and a
+//! bracket-balance check that decides when a multi-line entry is still
+//! incomplete.
+
+/// Yellow — operator tokens.
+pub const OPERATOR: u8 = 33;
+/// Cyan — variable tokens.
+pub const VARIABLE: u8 = 36;
+/// Green — command verbs.
+pub const COMMAND: u8 = 32;
+
+/// Wrap `text` in an ANSI SGR escape with colour `code`, resetting afterwards.
+pub fn paint(text: &str, code: u8) -> String {
+    format!("\x1b[{}m{}\x1b[0m", code, text)
+}
+
+/// True when every opening bracket in `input` is closed, i.e. the line is a
+/// complete expression as far as nesting is concerned.
+pub fn brackets_balanced(input: &str) -> bool {
+    let mut depth: i32 = 0;
+    for ch in input.chars() {
+        match ch {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    depth <= 0
+}
@@ -1,6 +1,6 @@
 use std::{
     cmp::Ordering,
-    collections::BinaryHeap,
+    collections::{BinaryHeap, VecDeque},
     fs::File,
     io::{self, BufRead},
     path::Path,
@@ -261,16 +261,42 @@ impl Graph {
         }
         strong_components.sort_by_key(|c| c[0]);
 
-        //TODO: redo
-        let start = 0;
-        let end = n - 1;
-
         let mut distances: DistancesMatrix = vec![vec![None; n]; n];
         let mut paths: PathsMatrix = vec![vec![None; n]; n];
         let mut selected_pairs = Vec::new();
 
-        for u in start..=end {
-            let (dist_u, prev_u) = self.dijkstra(u);
+        // Johnson's algorithm: Bellman-Ford potentials make every edge weight
+        // nonnegative so Dijkstra can run from each vertex, even with negative
+        // edges. A negative cycle leaves the potentials undefined.
+        let potentials = self.johnson_potentials();
+        let negative_cycle = potentials.is_none();
+
+        // Reweighted view (directed adjacency copy so `add_edge` mirroring is
+        // not re-applied); distances are corrected back after Dijkstra.
+        let reweighted = potentials.as_ref().map(|h| {
+            let mut rg = Graph::new(n, true);
+            for u in 0..n {
+                for &(v, w) in &self.adj[u] {
+                    rg.adj[u].push((v, w + h[u] - h[v]));
+                }
+            }
+            rg
+        });
+
+        for u in 0..n {
+            let (dist_u, prev_u) = match (&reweighted, &potentials) {
+                (Some(rg), Some(h)) => {
+                    let (mut dist, prev) = rg.dijkstra(u);
+                    for (v, d) in dist.iter_mut().enumerate() {
+                        if let Some(dv) = d {
+                            *dv = *dv - h[u] + h[v];
+                        }
+                    }
+                    (dist, prev)
+                }
+                _ => self.dijkstra(u),
+            };
+
             distances[u] = dist_u.clone();
 
             for v in 0..n {
@@ -303,7 +329,128 @@ impl Graph {
             radius: None,
             centers: Vec::new(),
             periphery: Vec::new(),
+            eulerian: self.eulerian_trail(),
+            negative_cycle,
+            closeness: Vec::new(),
+            betweenness: self.betweenness(),
+        }
+    }
+
+    /// Bellman-Ford from a virtual source joined to every vertex by a zero-weight
+    /// edge, yielding Johnson potentials `h[v]`. Returns `None` when a negative
+    /// cycle is reachable (some edge still relaxes after `n` passes).
+    fn johnson_potentials(&self) -> Option<Vec<f64>> {
+        let n = self.n;
+        let mut h = vec![0.0f64; n];
+
+        for _ in 0..n {
+            let mut changed = false;
+            for u in 0..n {
+                for &(v, w) in &self.adj[u] {
+                    if h[u] + w < h[v] - 1e-12 {
+                        h[v] = h[u] + w;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        for u in 0..n {
+            for &(v, w) in &self.adj[u] {
+                if h[u] + w < h[v] - 1e-12 {
+                    return None;
+                }
+            }
+        }
+        Some(h)
+    }
+
+    /// Betweenness centrality via Brandes' algorithm: BFS for unit-weight
+    /// graphs, Dijkstra otherwise. Undirected scores are halved because each
+    /// shortest path is counted from both endpoints.
+    pub fn betweenness(&self) -> Vec<f64> {
+        let n = self.n;
+        let mut bc = vec![0.0f64; n];
+        let unit = self
+            .adj
+            .iter()
+            .all(|row| row.iter().all(|&(_, w)| (w - 1.0).abs() < 1e-12));
+
+        for s in 0..n {
+            let mut stack: Vec<usize> = Vec::new();
+            let mut pred: Vec<Vec<usize>> = vec![Vec::new(); n];
+            let mut sigma = vec![0.0f64; n];
+            let mut dist = vec![f64::INFINITY; n];
+            sigma[s] = 1.0;
+            dist[s] = 0.0;
+
+            if unit {
+                let mut queue = VecDeque::new();
+                queue.push_back(s);
+                while let Some(v) = queue.pop_front() {
+                    stack.push(v);
+                    for &(w, _) in &self.adj[v] {
+                        if dist[w].is_infinite() {
+                            dist[w] = dist[v] + 1.0;
+                            queue.push_back(w);
+                        }
+                        if (dist[w] - (dist[v] + 1.0)).abs() < 1e-12 {
+                            sigma[w] += sigma[v];
+                            pred[w].push(v);
+                        }
+                    }
+                }
+            } else {
+                let mut heap = BinaryHeap::new();
+                let mut done = vec![false; n];
+                heap.push(State {
+                    cost: 0.0,
+                    position: s,
+                });
+                while let Some(State { cost, position }) = heap.pop() {
+                    if done[position] {
+                        continue;
+                    }
+                    done[position] = true;
+                    stack.push(position);
+                    for &(w, weight) in &self.adj[position] {
+                        let nd = cost + weight;
+                        if nd < dist[w] - 1e-12 {
+                            dist[w] = nd;
+                            sigma[w] = sigma[position];
+                            pred[w] = vec![position];
+                            heap.push(State {
+                                cost: nd,
+                                position: w,
+                            });
+                        } else if (nd - dist[w]).abs() < 1e-12 {
+                            sigma[w] += sigma[position];
+                            pred[w].push(position);
+                        }
+                    }
+                }
+            }
+
+            let mut delta = vec![0.0f64; n];
+            while let Some(w) = stack.pop() {
+                for &v in &pred[w] {
+                    delta[v] += (sigma[v] / sigma[w]) * (1.0 + delta[w]);
+                }
+                if w != s {
+                    bc[w] += delta[w];
+                }
+            }
+        }
+
+        if !self.directed {
+            for x in bc.iter_mut() {
+                *x /= 2.0;
+            }
         }
+        bc
     }
 
     pub fn weakly_connected_components(&self) -> Vec<Vec<usize>> {
@@ -397,6 +544,224 @@ impl Graph {
         }
     }
 
+    /// Detect an Eulerian circuit or open Eulerian path and return the vertex
+    /// trail that walks every edge exactly once, or `None` if none exists.
+    ///
+    /// Existence is decided by degrees plus connectivity of the nonzero-degree
+    /// vertices; the trail itself is produced with Hierholzer's algorithm.
+    pub fn eulerian_trail(&self) -> Option<Vec<usize>> {
+        let n = self.n;
+        if n == 0 {
+            return None;
+        }
+
+        let mut deg_out = vec![0usize; n];
+        let mut deg_in = vec![0usize; n];
+        for u in 0..n {
+            deg_out[u] = self.adj[u].len();
+            for &(v, _) in &self.adj[u] {
+                deg_in[v] += 1;
+            }
+        }
+
+        // All vertices touching an edge must lie in a single (weakly) connected
+        // component, otherwise no single trail can cover every edge.
+        let active: Vec<usize> = (0..n)
+            .filter(|&u| deg_out[u] > 0 || deg_in[u] > 0)
+            .collect();
+        if !active.is_empty() {
+            let comps = self.weakly_connected_components();
+            let home = comps
+                .iter()
+                .position(|c| c.contains(&active[0]))
+                .unwrap();
+            let all_together = active
+                .iter()
+                .all(|v| comps[home].contains(v));
+            if !all_together {
+                return None;
+            }
+        }
+
+        let start = if self.directed {
+            let mut start = active.first().copied().unwrap_or(0);
+            let mut plus_one = 0; // deg_out - deg_in == 1
+            let mut minus_one = 0; // deg_in - deg_out == 1
+            for u in 0..n {
+                let d = deg_out[u] as isize - deg_in[u] as isize;
+                match d {
+                    0 => {}
+                    1 => {
+                        plus_one += 1;
+                        start = u;
+                    }
+                    -1 => minus_one += 1,
+                    _ => return None,
+                }
+            }
+            if !((plus_one == 0 && minus_one == 0) || (plus_one == 1 && minus_one == 1)) {
+                return None;
+            }
+            start
+        } else {
+            let odd: Vec<usize> = (0..n).filter(|&u| deg_out[u] % 2 == 1).collect();
+            if odd.len() != 0 && odd.len() != 2 {
+                return None;
+            }
+            odd.first().copied().or_else(|| active.first().copied()).unwrap_or(0)
+        };
+
+        // Half-edge adjacency: a shared edge id lets an undirected edge be
+        // consumed from either endpoint exactly once.
+        let mut adj_he: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+        let mut edges = 0usize;
+        if self.directed {
+            for u in 0..n {
+                for &(v, _) in &self.adj[u] {
+                    adj_he[u].push((v, edges));
+                    edges += 1;
+                }
+            }
+        } else {
+            for u in 0..n {
+                for &(v, _) in &self.adj[u] {
+                    if u < v {
+                        adj_he[u].push((v, edges));
+                        adj_he[v].push((u, edges));
+                        edges += 1;
+                    } else if u == v {
+                        adj_he[u].push((v, edges));
+                        edges += 1;
+                    }
+                }
+            }
+        }
+        if edges == 0 {
+            return None;
+        }
+
+        let mut used = vec![false; edges];
+        let mut ptr = vec![0usize; n];
+        let mut stack = vec![start];
+        let mut circuit = Vec::new();
+
+        while let Some(&v) = stack.last() {
+            while ptr[v] < adj_he[v].len() && used[adj_he[v][ptr[v]].1] {
+                ptr[v] += 1;
+            }
+            if ptr[v] == adj_he[v].len() {
+                circuit.push(v);
+                stack.pop();
+            } else {
+                let (to, id) = adj_he[v][ptr[v]];
+                used[id] = true;
+                ptr[v] += 1;
+                stack.push(to);
+            }
+        }
+        circuit.reverse();
+
+        // A genuine trail visits every edge; a shorter walk means the edges were
+        // not all reachable from the chosen start.
+        if circuit.len() != edges + 1 {
+            return None;
+        }
+        Some(circuit)
+    }
+
+    /// Enumerate every simple path of exactly `len` edges starting at `start`,
+    /// returning the vertex sequences. Callers can post-filter the result by any
+    /// attribute (degree, weight thresholds, …). Directedness is honoured by
+    /// expanding only `self.adj`, and no vertex already on the current path is
+    /// revisited.
+    pub fn paths_of_length(&self, start: Vertex, len: usize) -> Vec<Vec<Vertex>> {
+        let mut results = Vec::new();
+        let mut visited = vec![false; self.n];
+        let mut path = vec![start];
+        visited[start] = true;
+        self.dfs_paths(start, len, &mut visited, &mut path, &mut results);
+        results
+    }
+
+    fn dfs_paths(
+        &self,
+        u: Vertex,
+        remaining: usize,
+        visited: &mut [bool],
+        path: &mut Vec<Vertex>,
+        results: &mut Vec<Vec<Vertex>>,
+    ) {
+        if remaining == 0 {
+            results.push(path.clone());
+            return;
+        }
+        for &(v, _) in &self.adj[u] {
+            if !visited[v] {
+                visited[v] = true;
+                path.push(v);
+                self.dfs_paths(v, remaining - 1, visited, path, results);
+                path.pop();
+                visited[v] = false;
+            }
+        }
+    }
+
+    /// A* single-pair shortest path ordered by `g(v) + h(v)`, returning the
+    /// optimal distance and reconstructed path, or `None` if `goal` is
+    /// unreachable.
+    ///
+    /// `heuristic` must be an *admissible* lower bound on the remaining cost to
+    /// `goal` (never overestimating) for the result to be optimal; the
+    /// [`zero_heuristic`] below is always admissible and reduces A* to Dijkstra.
+    pub fn astar<F: Fn(Vertex) -> Weight>(
+        &self,
+        start: Vertex,
+        goal: Vertex,
+        heuristic: F,
+    ) -> Option<(Weight, Vec<Vertex>)> {
+        let n = self.n;
+        let mut g_score = vec![f64::INFINITY; n];
+        let mut came_from: Vec<Option<Vertex>> = vec![None; n];
+        let mut closed = vec![false; n];
+
+        g_score[start] = 0.0;
+        let mut heap = BinaryHeap::new();
+        heap.push(State {
+            cost: heuristic(start),
+            position: start,
+        });
+
+        while let Some(State { position, .. }) = heap.pop() {
+            if position == goal {
+                let mut path = vec![goal];
+                let mut cur = goal;
+                while let Some(p) = came_from[cur] {
+                    path.push(p);
+                    cur = p;
+                }
+                path.reverse();
+                return Some((g_score[goal], path));
+            }
+            if closed[position] {
+                continue;
+            }
+            closed[position] = true;
+
+            for &(neighbor, weight) in &self.adj[position] {
+                let tentative = g_score[position] + weight;
+                if tentative < g_score[neighbor] - 1e-12 {
+                    g_score[neighbor] = tentative;
+                    came_from[neighbor] = Some(position);
+                    heap.push(State {
+                        cost: tentative + heuristic(neighbor),
+                        position: neighbor,
+                    });
+                }
+            }
+        }
+        None
+    }
+
     pub fn dijkstra(&self, start: usize) -> (Vec<Option<f64>>, Vec<Option<usize>>) {
         let n = self.n;
         let mut distances = vec![None; n];
@@ -431,10 +796,16 @@ impl Graph {
     }
 }
 
+/// The trivial admissible heuristic: a constant zero lower bound, which makes
+/// [`Graph::astar`] behave exactly like Dijkstra.
+pub fn zero_heuristic(_v: Vertex) -> Weight {
+    0.0
+}
+
 #[derive(Copy, Clone, PartialEq)]
-struct State {
-    cost: f64,
-    position: usize,
+pub(crate) struct State {
+    pub(crate) cost: f64,
+    pub(crate) position: usize,
 }
 
 impl Eq for State {}
@@ -451,3 +822,46 @@ impl PartialOrd for State {
         Some(self.cmp(other))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn betweenness_weighted_triangle() {
+        // 0→1(1), 0→2(2), 1→2(1): the two shortest 0→2 paths are 0→2 and
+        // 0→1→2, so vertex 1 lies on exactly half of them and scores 0.5.
+        let mut g = Graph::new(3, true);
+        g.add_edge(0, 1, 1.0);
+        g.add_edge(0, 2, 2.0);
+        g.add_edge(1, 2, 1.0);
+
+        let bc = g.betweenness();
+        assert!(close(bc[0], 0.0));
+        assert!(close(bc[1], 0.5));
+        assert!(close(bc[2], 0.0));
+    }
+
+    #[test]
+    fn eulerian_directed_cycle() {
+        let mut g = Graph::new(3, true);
+        g.add_edge(0, 1, 1.0);
+        g.add_edge(1, 2, 1.0);
+        g.add_edge(2, 0, 1.0);
+
+        let trail = g.eulerian_trail().expect("cycle has an Eulerian circuit");
+        assert_eq!(trail.len(), 4); // edges + 1
+    }
+
+    #[test]
+    fn eulerian_absent() {
+        let mut g = Graph::new(3, true);
+        g.add_edge(0, 1, 1.0);
+        g.add_edge(0, 2, 1.0);
+        assert!(g.eulerian_trail().is_none());
+    }
+}
@@ -3,6 +3,7 @@ use std::fs;
 use crate::task4::{graph::Graph, task_results::GraphResults};
 
 mod args_parser;
+mod flow;
 mod graph;
 mod task_results;
 
@@ -46,8 +47,33 @@ pub fn run_task_4(args: &[String]) -> Result<(), String> {
 
     let mut results = graph.analyze();
     results.compute_graph_metrics();
+    results.compute_centrality();
 
-    let results_str = format_text(&results);
+    let mut results_str = format_text(&results);
+
+    // When both a source (-n) and sink (-d) are given, also report the
+    // min-cost max-flow between them.
+    if let (Some(src), Some(dst)) = (cli_args.start_vertex, cli_args.end_vertex) {
+        let mut net = flow::FlowNetwork::from_graph(&graph);
+        let (max_flow, min_cost) = net.min_cost_max_flow(src - 1, dst - 1, None);
+        results_str.push_str(&format!(
+            "\n10. Min-cost max-flow from {} to {}:\n   max flow = {}, min cost = {}\n",
+            src, dst, max_flow, min_cost
+        ));
+
+        results_str.push_str(&format!("\n11. A* shortest path from {} to {}:\n", src, dst));
+        match graph.astar(src - 1, dst - 1, graph::zero_heuristic) {
+            Some((dist, path)) => results_str.push_str(&format!(
+                "   {} (length {})\n",
+                path.iter()
+                    .map(|v| (v + 1).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> "),
+                dist
+            )),
+            None => results_str.push_str("   no path\n"),
+        }
+    }
 
     output_results(&results_str, cli_args.output_file.as_deref())
         .expect("failed to output results");
@@ -154,8 +180,8 @@ pub fn format_text(results: &GraphResults) -> String {
     }
 
     s.push_str("6. Graph metrics:\n");
-    if results.directed {
-        s.push_str("   Diameter: None\n   Radius: None\n   Central vertices: None\n   Peripheral vertices: None\n\n");
+    if results.negative_cycle {
+        s.push_str("   Negative cycle detected — distances are undefined.\n\n");
     } else {
         let diameter_str = results
             .diameter
@@ -227,5 +253,38 @@ pub fn format_text(results: &GraphResults) -> String {
         }
     }
 
+    s.push_str("\n8. Eulerian path / circuit:\n");
+    match &results.eulerian {
+        Some(trail) => {
+            let closed = trail.first() == trail.last();
+            s.push_str(if closed {
+                "   Eulerian circuit: "
+            } else {
+                "   Eulerian path: "
+            });
+            s.push_str(
+                &trail
+                    .iter()
+                    .map(|v| (v + 1).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> "),
+            );
+            s.push('\n');
+        }
+        None => s.push_str("   no Eulerian path\n"),
+    }
+
+    if !results.closeness.is_empty() {
+        s.push_str("\n9. Centrality:\n");
+        let fmt = |xs: &[f64]| {
+            xs.iter()
+                .map(|x| format!("{:.4}", x))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        s.push_str(&format!("   Closeness:   [{}]\n", fmt(&results.closeness)));
+        s.push_str(&format!("   Betweenness: [{}]\n", fmt(&results.betweenness)));
+    }
+
     s
 }
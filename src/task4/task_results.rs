@@ -15,6 +15,12 @@ pub struct GraphResults {
     pub radius: Option<f64>,
     pub centers: Vec<usize>,
     pub periphery: Vec<usize>,
+
+    pub eulerian: Option<Vec<usize>>,
+    pub negative_cycle: bool,
+
+    pub closeness: Vec<f64>,
+    pub betweenness: Vec<f64>,
 }
 
 pub type Distance = Option<f64>; // None = +Infinity
@@ -40,7 +46,11 @@ impl GraphResults {
                 }
             }
 
-            eccentricities[u] = if has_infinite {
+            // For a directed graph unreachable vertices are the norm (it is
+            // rarely strongly connected), so eccentricity is the greatest
+            // *finite* distance. For undirected graphs a missing distance means
+            // a genuinely disconnected component, giving infinite eccentricity.
+            eccentricities[u] = if has_infinite && !self.directed {
                 f64::INFINITY
             } else {
                 max_dist
@@ -54,6 +64,35 @@ impl GraphResults {
         self.periphery = Self::filter_vertices_by_value(&eccentricities, self.diameter);
     }
 
+    /// Closeness centrality from the all-pairs distance matrix:
+    /// `(reachable - 1) / sum_of_finite_distances`, or `0` when a vertex reaches
+    /// nothing. Betweenness is filled in during analysis, where the edge
+    /// structure Brandes' algorithm needs is still available.
+    pub fn compute_centrality(&mut self) {
+        let n = self.distances.len();
+        let mut closeness = vec![0.0f64; n];
+
+        (0..n).for_each(|u| {
+            let mut sum = 0.0;
+            let mut reachable = 0usize;
+            for v in 0..n {
+                if let Some(d) = self.distances[u][v] {
+                    reachable += 1;
+                    if u != v {
+                        sum += d;
+                    }
+                }
+            }
+            closeness[u] = if sum > 0.0 {
+                (reachable as f64 - 1.0) / sum
+            } else {
+                0.0
+            };
+        });
+
+        self.closeness = closeness;
+    }
+
     fn filter_vertices_by_value(eccentricities: &[f64], value: Option<f64>) -> Vec<usize> {
         eccentricities
             .iter()